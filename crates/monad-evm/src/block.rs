@@ -0,0 +1,225 @@
+//! Block-level execution on top of [`MonadEvm`].
+
+use alloy_evm::{Database, Evm};
+use alloy_primitives::{Address, Bloom, Bytes, Log};
+use revm::{
+    context::TxEnv,
+    context_interface::result::{EVMError, ExecutionResult, ResultAndState},
+    handler::PrecompileProvider,
+    interpreter::InterpreterResult,
+    DatabaseCommit, Inspector,
+};
+
+use crate::{MonadContext, MonadEvm};
+
+/// A system call to run before or after a block's transactions.
+///
+/// Run before (via [`MonadBlockExecutor::new`]'s `pre_block_system_calls`): the EIP-4788
+/// beacon-root contract, the EIP-2935 block-hash history contract. Run after (via
+/// `post_block_system_calls`): the EIP-7002/7251 withdrawal and consolidation contracts,
+/// whose encoded output becomes a [`BlockExecutionResult::requests`] entry.
+#[derive(Debug, Clone)]
+pub struct PreBlockSystemCall {
+    /// Caller the system call is attributed to.
+    pub caller: Address,
+    /// Address of the system contract being invoked.
+    pub contract: Address,
+    /// Calldata passed to the system contract.
+    pub data: Bytes,
+}
+
+/// Receipt produced for a single transaction within a block.
+#[derive(Debug, Clone)]
+pub struct MonadReceipt {
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// Cumulative gas used by the block up to and including this transaction.
+    pub cumulative_gas_used: u64,
+    /// Logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// Logs bloom computed from [`MonadReceipt::logs`].
+    pub logs_bloom: Bloom,
+}
+
+/// Aggregate result of executing an ordered list of transactions against a block.
+#[derive(Debug, Clone)]
+pub struct BlockExecutionResult {
+    /// Per-transaction receipts, in transaction order.
+    pub receipts: Vec<MonadReceipt>,
+    /// Total gas used by the block.
+    pub gas_used: u64,
+    /// EIP-7685 execution-layer requests accumulated while executing the block.
+    pub requests: Vec<Bytes>,
+    /// Logs bloom for the entire block, i.e. the union of every receipt's bloom.
+    pub logs_bloom: Bloom,
+}
+
+/// Identifies which step of block execution produced a [`BlockExecutionError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockStep {
+    /// The `n`-th (0-indexed) entry of the `pre_block_system_calls` list passed to
+    /// [`MonadBlockExecutor::new`].
+    PreBlockSystemCall(usize),
+    /// The `n`-th (0-indexed) transaction of the list passed to
+    /// [`MonadBlockExecutor::execute_block`].
+    Transaction(usize),
+    /// The `n`-th (0-indexed) entry of the `post_block_system_calls` list passed to
+    /// [`MonadBlockExecutor::new`].
+    PostBlockSystemCall(usize),
+}
+
+/// Error produced while executing a block, identifying which step failed.
+#[derive(Debug)]
+pub struct BlockExecutionError<DB: Database> {
+    /// The step that failed.
+    pub step: BlockStep,
+    /// Underlying EVM error.
+    pub error: EVMError<DB::Error>,
+}
+
+/// Drives an ordered list of [`TxEnv`]s against a single [`MonadEvm`], returning a
+/// [`BlockExecutionResult`].
+///
+/// Configured `pre_block_system_calls` run first via
+/// [`Evm::transact_system_call`](alloy_evm::Evm::transact_system_call), then each
+/// transaction is executed with [`Evm::transact_raw`](alloy_evm::Evm::transact_raw), its
+/// resulting state is committed, and a [`MonadReceipt`] is accumulated, then
+/// `post_block_system_calls` run the same way. This mirrors the block-builder loop used by
+/// block producers.
+#[allow(missing_debug_implementations)] // MonadEvm doesn't impl Debug
+pub struct MonadBlockExecutor<DB: Database, I, P> {
+    evm: MonadEvm<DB, I, P>,
+    pre_block_system_calls: Vec<PreBlockSystemCall>,
+    post_block_system_calls: Vec<PreBlockSystemCall>,
+}
+
+impl<DB, I, P> MonadBlockExecutor<DB, I, P>
+where
+    DB: Database + DatabaseCommit,
+    I: Inspector<MonadContext<DB>>,
+    P: PrecompileProvider<MonadContext<DB>, Output = InterpreterResult>,
+{
+    /// Creates a new block executor around `evm`, running `pre_block_system_calls` before
+    /// any transaction and `post_block_system_calls` after the last one.
+    pub const fn new(
+        evm: MonadEvm<DB, I, P>,
+        pre_block_system_calls: Vec<PreBlockSystemCall>,
+        post_block_system_calls: Vec<PreBlockSystemCall>,
+    ) -> Self {
+        Self { evm, pre_block_system_calls, post_block_system_calls }
+    }
+
+    /// Executes `txs` in order, returning the aggregate [`BlockExecutionResult`].
+    ///
+    /// On the first error, execution stops and the [`BlockStep`] and [`EVMError`] of the
+    /// failing step are returned rather than aborting silently.
+    pub fn execute_block(
+        mut self,
+        txs: Vec<TxEnv>,
+    ) -> Result<BlockExecutionResult, BlockExecutionError<DB>> {
+        let mut requests = Vec::new();
+
+        for (call_index, call) in self.pre_block_system_calls.clone().into_iter().enumerate() {
+            let ResultAndState { result, state } = self
+                .evm
+                .transact_system_call(call.caller, call.contract, call.data)
+                .map_err(|error| BlockExecutionError { step: BlockStep::PreBlockSystemCall(call_index), error })?;
+            self.evm.ctx_mut().journaled_state.database.commit(state);
+
+            // Request-producing system calls (e.g. the EIP-7002/7251 withdrawal and
+            // consolidation contracts) return the encoded request as their output.
+            if let ExecutionResult::Success { output, .. } = &result {
+                let output = output.data();
+                if !output.is_empty() {
+                    requests.push(output.clone());
+                }
+            }
+        }
+
+        let mut receipts = Vec::with_capacity(txs.len());
+        let mut cumulative_gas_used = 0u64;
+        let mut block_logs_bloom = Bloom::default();
+
+        for (tx_index, tx) in txs.into_iter().enumerate() {
+            let ResultAndState { result, state } = self
+                .evm
+                .transact_raw(tx)
+                .map_err(|error| BlockExecutionError { step: BlockStep::Transaction(tx_index), error })?;
+
+            self.evm.ctx_mut().journaled_state.database.commit(state);
+
+            cumulative_gas_used += result.gas_used();
+            let success = result.is_success();
+            let logs: Vec<Log> = match &result {
+                ExecutionResult::Success { logs, .. } => logs.clone(),
+                _ => Vec::new(),
+            };
+
+            let logs_bloom = bloom_for_logs(&logs);
+            block_logs_bloom.accrue_bloom(&logs_bloom);
+
+            receipts.push(MonadReceipt { success, cumulative_gas_used, logs, logs_bloom });
+        }
+
+        for (call_index, call) in self.post_block_system_calls.clone().into_iter().enumerate() {
+            let ResultAndState { result, state } = self
+                .evm
+                .transact_system_call(call.caller, call.contract, call.data)
+                .map_err(|error| BlockExecutionError { step: BlockStep::PostBlockSystemCall(call_index), error })?;
+            self.evm.ctx_mut().journaled_state.database.commit(state);
+
+            if let ExecutionResult::Success { output, .. } = &result {
+                let output = output.data();
+                if !output.is_empty() {
+                    requests.push(output.clone());
+                }
+            }
+        }
+
+        Ok(BlockExecutionResult { receipts, gas_used: cumulative_gas_used, requests, logs_bloom: block_logs_bloom })
+    }
+}
+
+/// Computes the logs bloom for a single transaction's logs.
+fn bloom_for_logs(logs: &[Log]) -> Bloom {
+    let mut bloom = Bloom::default();
+    for log in logs {
+        bloom.accrue_log(log);
+    }
+    bloom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes, LogData};
+
+    #[test]
+    fn bloom_for_logs_is_empty_with_no_logs() {
+        assert_eq!(bloom_for_logs(&[]), Bloom::default());
+    }
+
+    #[test]
+    fn bloom_for_logs_matches_manual_accrual() {
+        let log = Log {
+            address: address!("0000000000000000000000000000000000000001"),
+            data: LogData::new_unchecked(
+                vec![b256!("0000000000000000000000000000000000000000000000000000000000000001")],
+                bytes!("deadbeef"),
+            ),
+        };
+
+        let mut expected = Bloom::default();
+        expected.accrue_log(&log);
+
+        assert_eq!(bloom_for_logs(&[log]), expected);
+    }
+
+    #[test]
+    fn block_step_variants_are_distinguishable() {
+        assert_ne!(BlockStep::PreBlockSystemCall(0), BlockStep::Transaction(0));
+        assert_ne!(BlockStep::PreBlockSystemCall(0), BlockStep::PreBlockSystemCall(1));
+        assert_ne!(BlockStep::Transaction(0), BlockStep::PostBlockSystemCall(0));
+        assert_ne!(BlockStep::PreBlockSystemCall(0), BlockStep::PostBlockSystemCall(0));
+    }
+}
@@ -0,0 +1,136 @@
+//! Type-erased [`MonadEvm`] variant that boxes its database and takes its inspector as a
+//! lifetime-generic trait object, so a single concrete type can be shared across inspector
+//! and database combinations without per-combination monomorphization.
+
+use alloy_evm::Database;
+use monad_revm::{precompiles::MonadPrecompiles, MonadCfgEnv};
+use revm::{inspector::NoOpInspector, Context, Inspector};
+use std::error::Error as StdError;
+
+use crate::{MonadContext, MonadEvm, MonadEvmFactory};
+
+/// A boxed [`Database`] with a fixed error type, so it can be shared across EVM instances
+/// without monomorphizing per concrete database.
+pub type MonadDynDatabase<E> = Box<dyn Database<Error = E> + Send>;
+
+/// A lifetime-generic, type-erased [`Inspector`](revm::Inspector), so callers can swap
+/// inspectors at runtime (tracing on/off, fork vs. local) without paying per-inspector
+/// monomorphization cost.
+pub type MonadDynInspector<'a, E> = &'a mut dyn Inspector<MonadContext<MonadDynDatabase<E>>>;
+
+/// Type-erased [`MonadEvm`]: a boxed database and a lifetime-generic trait-object
+/// inspector, carried by a single non-generic handle.
+///
+/// The existing static-dispatch [`MonadEvm<DB, I, P>`] remains available for hot paths that
+/// can afford monomorphizing per database/inspector combination.
+pub type MonadEvmDyn<'a, E> = MonadEvm<MonadDynDatabase<E>, MonadDynInspector<'a, E>, MonadPrecompiles>;
+
+impl MonadEvmFactory {
+    /// Creates a [`MonadEvmDyn`] from a boxed `db` and a borrowed `inspector`, monomorphized
+    /// only once per database error type `E` rather than once per concrete database and
+    /// inspector.
+    pub fn create_evm_dyn<'a, E: StdError + Send + Sync + 'static>(
+        &self,
+        db: MonadDynDatabase<E>,
+        input: alloy_evm::EvmEnv<monad_revm::MonadSpecId>,
+        inspector: MonadDynInspector<'a, E>,
+    ) -> MonadEvmDyn<'a, E> {
+        self.create_evm_dyn_with_inspect(db, input, inspector, true)
+    }
+
+    /// Creates a [`MonadEvmDyn`] with tracing disabled, using a borrowed [`NoOpInspector`]
+    /// behind the same [`MonadDynInspector`] handle.
+    pub fn create_evm_dyn_no_inspector<'a, E: StdError + Send + Sync + 'static>(
+        &self,
+        db: MonadDynDatabase<E>,
+        input: alloy_evm::EvmEnv<monad_revm::MonadSpecId>,
+        noop: &'a mut NoOpInspector,
+    ) -> MonadEvmDyn<'a, E> {
+        self.create_evm_dyn_with_inspect(db, input, noop, false)
+    }
+
+    fn create_evm_dyn_with_inspect<'a, E: StdError + Send + Sync + 'static>(
+        &self,
+        db: MonadDynDatabase<E>,
+        input: alloy_evm::EvmEnv<monad_revm::MonadSpecId>,
+        inspector: MonadDynInspector<'a, E>,
+        inspect: bool,
+    ) -> MonadEvmDyn<'a, E> {
+        let spec_id = input.cfg_env.spec;
+        let monad_cfg = MonadCfgEnv::from(input.cfg_env);
+        MonadEvm::new(
+            Context::monad()
+                .with_db(db)
+                .with_block(input.block_env)
+                .with_cfg(monad_cfg)
+                .build_monad_with_inspector(inspector)
+                .with_precompiles(MonadPrecompiles::new_with_spec(spec_id)),
+            inspect,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_evm::{Evm, EvmEnv};
+    use alloy_primitives::{Address, TxKind, B256, U256};
+    use monad_revm::MonadSpecId;
+    use revm::{
+        bytecode::Bytecode as RevmBytecode,
+        context::{BlockEnv, CfgEnv, TxEnv},
+        database::Database as RevmDatabase,
+        state::AccountInfo,
+    };
+    use std::convert::Infallible;
+
+    #[derive(Default)]
+    struct MockDb;
+
+    impl RevmDatabase for MockDb {
+        type Error = Infallible;
+
+        fn basic(&mut self, _address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+            Ok(Some(AccountInfo { balance: U256::from(1_000_000_000_000_u64), ..Default::default() }))
+        }
+
+        fn code_by_hash(&mut self, _code_hash: B256) -> Result<RevmBytecode, Self::Error> {
+            Ok(RevmBytecode::default())
+        }
+
+        fn storage(&mut self, _address: Address, _index: U256) -> Result<U256, Self::Error> {
+            Ok(U256::ZERO)
+        }
+
+        fn block_hash(&mut self, _number: u64) -> Result<B256, Self::Error> {
+            Ok(B256::ZERO)
+        }
+    }
+
+    fn test_env() -> EvmEnv<MonadSpecId> {
+        EvmEnv { block_env: BlockEnv::default(), cfg_env: CfgEnv::new_with_spec(MonadSpecId::default()) }
+    }
+
+    #[test]
+    fn create_evm_dyn_no_inspector_can_transact() {
+        let db: MonadDynDatabase<Infallible> = Box::new(MockDb);
+        let mut noop = NoOpInspector {};
+        let mut evm = MonadEvmFactory.create_evm_dyn_no_inspector(db, test_env(), &mut noop);
+
+        let tx =
+            TxEnv { caller: Address::ZERO, kind: TxKind::Call(Address::ZERO), gas_limit: 21_000, ..Default::default() };
+        assert!(evm.transact_raw(tx).is_ok());
+    }
+
+    #[test]
+    fn create_evm_dyn_can_transact_with_an_inspector() {
+        let db: MonadDynDatabase<Infallible> = Box::new(MockDb);
+        let mut inspector = NoOpInspector {};
+        let dyn_inspector: MonadDynInspector<'_, Infallible> = &mut inspector;
+        let mut evm = MonadEvmFactory.create_evm_dyn(db, test_env(), dyn_inspector);
+
+        let tx =
+            TxEnv { caller: Address::ZERO, kind: TxKind::Call(Address::ZERO), gas_limit: 21_000, ..Default::default() };
+        assert!(evm.transact_raw(tx).is_ok());
+    }
+}
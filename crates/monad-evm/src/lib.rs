@@ -15,14 +15,30 @@ use monad_revm::{
 };
 use revm::{
     context::{BlockEnv, TxEnv},
-    context_interface::result::{EVMError, HaltReason, ResultAndState},
+    context_interface::result::{EVMError, ExecutionResult, HaltReason, ResultAndState},
     handler::PrecompileProvider,
     inspector::NoOpInspector,
     interpreter::InterpreterResult,
     Context, ExecuteEvm, InspectEvm, Inspector, SystemCallEvm,
 };
-use std::ops::{Deref, DerefMut};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+mod block;
+mod dyn_evm;
+mod overrides;
+mod precompiles;
+pub mod tracing;
 
+pub use block::{
+    BlockExecutionError, BlockExecutionResult, BlockStep, MonadBlockExecutor, MonadReceipt,
+    PreBlockSystemCall,
+};
+pub use dyn_evm::{MonadDynDatabase, MonadDynInspector, MonadEvmDyn};
+pub use overrides::{AccountOverride, BlockOverride, OverrideJournal};
+pub use precompiles::{MonadPrecompilesBuilder, MonadPrecompilesWithExtra, Precompile};
 // Re-export monad-revm types for external users
 pub use monad_revm::{handler::MonadHandler, MonadContext};
 
@@ -62,6 +78,122 @@ impl<DB: Database, I, P> MonadEvm<DB, I, P> {
     }
 }
 
+impl<DB, P> MonadEvm<DB, tracing::MonadStructLogger, P>
+where
+    DB: Database,
+    P: PrecompileProvider<MonadContext<DB>, Output = InterpreterResult>,
+{
+    /// Executes `tx` with [`set_inspector_enabled`](Evm::set_inspector_enabled) forced on,
+    /// returning both the transaction result and the assembled [`GethTrace`].
+    pub fn transact_with_struct_logs(
+        &mut self,
+        tx: TxEnv,
+    ) -> Result<(ResultAndState<HaltReason>, tracing::GethTrace), EVMError<DB::Error>> {
+        let prior_inspect = self.inspect;
+        self.inspect = true;
+        let result_and_state = self.transact_raw(tx);
+        self.inspect = prior_inspect;
+        let result_and_state = result_and_state?;
+
+        let logger = std::mem::take(&mut self.inner.0.inspector);
+        let (gas_used, failed, return_value) = match &result_and_state.result {
+            ExecutionResult::Success { gas_used, output, .. } => {
+                (*gas_used, false, output.data().clone())
+            }
+            ExecutionResult::Revert { gas_used, output } => (*gas_used, true, output.clone()),
+            ExecutionResult::Halt { gas_used, .. } => (*gas_used, true, Bytes::new()),
+        };
+        let trace = logger.into_trace(gas_used, failed, return_value);
+
+        Ok((result_and_state, trace))
+    }
+}
+
+impl<DB, I, P> MonadEvm<DB, I, P>
+where
+    DB: Database,
+    P: PrecompileProvider<MonadContext<DB>, Output = InterpreterResult>,
+{
+    /// Addresses of every precompile currently reachable by this EVM, both injected and
+    /// spec-derived.
+    pub fn active_precompile_addresses(&self) -> Box<dyn Iterator<Item = Address> + '_> {
+        self.inner.0.precompiles.warm_addresses()
+    }
+}
+
+impl<DB, P> MonadEvm<DB, tracing::MonadCallTracer, P>
+where
+    DB: Database,
+    P: PrecompileProvider<MonadContext<DB>, Output = InterpreterResult>,
+{
+    /// Executes `tx` with the inspector forced on, returning both the transaction result
+    /// and the assembled call-frame tree (Geth's `callTracer` format).
+    pub fn transact_with_call_tracer(
+        &mut self,
+        tx: TxEnv,
+    ) -> Result<(ResultAndState<HaltReason>, Option<tracing::CallFrame>), EVMError<DB::Error>> {
+        let prior_inspect = self.inspect;
+        self.inspect = true;
+        let result_and_state = self.transact_raw(tx);
+        self.inspect = prior_inspect;
+        let result_and_state = result_and_state?;
+
+        let tracer = std::mem::take(&mut self.inner.0.inspector);
+        Ok((result_and_state, tracer.into_root_frame()))
+    }
+}
+
+impl<DB, P> MonadEvm<DB, tracing::MonadPrestateTracer, P>
+where
+    DB: Database,
+    P: PrecompileProvider<MonadContext<DB>, Output = InterpreterResult>,
+{
+    /// Executes `tx` with the inspector forced on, then loads the pre- (and, in diff mode,
+    /// post-) execution state of every touched account into a [`PrestateTrace`].
+    ///
+    /// Pre-state is read from the database *before* committing `tx`'s resulting state, so
+    /// callers must not commit the returned [`ResultAndState`] until after this call
+    /// returns.
+    pub fn transact_with_prestate_tracer(
+        &mut self,
+        tx: TxEnv,
+    ) -> Result<(ResultAndState<HaltReason>, tracing::PrestateTrace), EVMError<DB::Error>> {
+        let prior_inspect = self.inspect;
+        self.inspect = true;
+        let result_and_state = self.transact_raw(tx);
+        self.inspect = prior_inspect;
+        let result_and_state = result_and_state?;
+
+        let tracer = std::mem::take(&mut self.inner.0.inspector);
+        let touched = tracer.touched_addresses().to_vec();
+        let storage = tracer.storage().clone();
+        let pre = tracing::MonadPrestateTracer::load_account_states(
+            &touched,
+            &storage,
+            &mut self.inner.0.ctx.journaled_state.database,
+        );
+
+        // Nothing has been committed to the database, so the post-execution state comes from
+        // the returned `ResultAndState` rather than from a second database read.
+        let post = if tracer.mode() == tracing::PrestateTracerMode::Diff {
+            let post_state = touched.iter().filter_map(|address| {
+                let account = result_and_state.state.get(address)?;
+                let code = account.info.code.as_ref().map(|code| Bytes::copy_from_slice(code.bytes_slice()));
+                let storage = account.storage.iter().map(|(slot, value)| {
+                    (tracing::b256_from_u256(*slot), tracing::b256_from_u256(value.present_value))
+                });
+                let (balance, nonce) = (account.info.balance, account.info.nonce);
+                Some((*address, tracing::AccountState { balance, nonce, code, storage: storage.collect() }))
+            });
+            post_state.collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok((result_and_state, tracing::PrestateTrace { pre, post }))
+    }
+}
+
 impl<DB: Database, I, P> Deref for MonadEvm<DB, I, P> {
     type Target = MonadContext<DB>;
 
@@ -206,3 +338,65 @@ impl EvmFactory for MonadEvmFactory {
         }
     }
 }
+
+impl MonadEvmFactory {
+    /// Creates a [`MonadBlockExecutor`] that runs `pre_block_system_calls`, then drives an
+    /// ordered list of transactions against `db`, then runs `post_block_system_calls`,
+    /// accumulating a [`BlockExecutionResult`].
+    ///
+    /// Uses the non-inspecting [`create_evm`](EvmFactory::create_evm) path; use
+    /// [`MonadBlockExecutor::new`] directly if tracing is required.
+    pub fn create_block_executor<DB: Database + revm::DatabaseCommit>(
+        &self,
+        db: DB,
+        input: EvmEnv<MonadSpecId>,
+        pre_block_system_calls: Vec<PreBlockSystemCall>,
+        post_block_system_calls: Vec<PreBlockSystemCall>,
+    ) -> MonadBlockExecutor<DB, NoOpInspector, MonadPrecompiles> {
+        MonadBlockExecutor::new(self.create_evm(db, input), pre_block_system_calls, post_block_system_calls)
+    }
+
+    /// Like [`create_evm`](EvmFactory::create_evm), but layers `extra_precompiles` on top
+    /// of the spec-derived [`MonadPrecompiles`] defaults, taking priority over them.
+    pub fn create_evm_with_precompiles<DB: Database>(
+        &self,
+        db: DB,
+        input: EvmEnv<MonadSpecId>,
+        extra_precompiles: MonadPrecompilesBuilder,
+    ) -> MonadEvm<DB, NoOpInspector, MonadPrecompilesWithExtra> {
+        let spec_id = input.cfg_env.spec;
+        let monad_cfg = MonadCfgEnv::from(input.cfg_env);
+        MonadEvm {
+            inner: Context::monad()
+                .with_db(db)
+                .with_block(input.block_env)
+                .with_cfg(monad_cfg)
+                .build_monad_with_inspector(NoOpInspector {})
+                .with_precompiles(extra_precompiles.build(MonadPrecompiles::new_with_spec(spec_id))),
+            inspect: false,
+        }
+    }
+
+    /// Like [`create_evm_with_inspector`](EvmFactory::create_evm_with_inspector), but
+    /// layers `extra_precompiles` on top of the spec-derived [`MonadPrecompiles`]
+    /// defaults, taking priority over them.
+    pub fn create_evm_with_inspector_and_precompiles<DB: Database, I: Inspector<MonadContext<DB>>>(
+        &self,
+        db: DB,
+        input: EvmEnv<MonadSpecId>,
+        inspector: I,
+        extra_precompiles: MonadPrecompilesBuilder,
+    ) -> MonadEvm<DB, I, MonadPrecompilesWithExtra> {
+        let spec_id = input.cfg_env.spec;
+        let monad_cfg = MonadCfgEnv::from(input.cfg_env);
+        MonadEvm {
+            inner: Context::monad()
+                .with_db(db)
+                .with_block(input.block_env)
+                .with_cfg(monad_cfg)
+                .build_monad_with_inspector(inspector)
+                .with_precompiles(extra_precompiles.build(MonadPrecompiles::new_with_spec(spec_id))),
+            inspect: true,
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! State and block-environment overrides for speculative / simulation calls (e.g.
+//! `eth_call`, `eth_simulateV1`).
+
+use alloy_evm::{Database, Evm};
+use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
+use revm::{bytecode::Bytecode, context::BlockEnv, primitives::KECCAK_EMPTY, state::Account};
+use std::collections::HashMap;
+
+use crate::MonadEvm;
+
+/// Override applied to a single account before executing a call.
+///
+/// `state` fully replaces the account's storage; `state_diff` patches individual slots on
+/// top of the existing storage. Setting both is a caller error; `apply_overrides` prefers
+/// `state` when both are present.
+#[derive(Debug, Clone, Default)]
+pub struct AccountOverride {
+    /// Replacement balance.
+    pub balance: Option<U256>,
+    /// Replacement nonce.
+    pub nonce: Option<u64>,
+    /// Replacement bytecode.
+    pub code: Option<Bytes>,
+    /// Storage that fully replaces the account's existing storage.
+    pub state: Option<HashMap<B256, B256>>,
+    /// Storage slots patched on top of the account's existing storage.
+    pub state_diff: Option<HashMap<B256, B256>>,
+}
+
+/// Override applied to the [`BlockEnv`] before executing a call.
+#[derive(Debug, Clone, Default)]
+pub struct BlockOverride {
+    pub number: Option<U256>,
+    pub timestamp: Option<u64>,
+    pub gas_limit: Option<u64>,
+    pub coinbase: Option<Address>,
+    pub base_fee: Option<U256>,
+}
+
+/// Snapshot of an overridden account's prior state, used to revert
+/// [`apply_overrides`](MonadEvm::apply_overrides) once the simulation call has run.
+struct AccountSnapshot {
+    address: Address,
+    account: Option<Account>,
+}
+
+/// Snapshot of the prior [`BlockEnv`], used to revert a [`BlockOverride`].
+struct BlockSnapshot(BlockEnv);
+
+/// Journal returned by [`MonadEvm::apply_overrides`] that restores the pre-override state
+/// when passed to [`MonadEvm::revert_overrides`].
+#[must_use = "overrides must be reverted via `MonadEvm::revert_overrides` to reuse the EVM"]
+pub struct OverrideJournal {
+    accounts: Vec<AccountSnapshot>,
+    block: Option<BlockSnapshot>,
+}
+
+/// Computes the `code_hash` that must accompany an [`AccountOverride::code`] replacement,
+/// matching how `Bytecode` hashing treats empty code.
+fn code_hash_for(code: &Bytes) -> B256 {
+    if code.is_empty() {
+        KECCAK_EMPTY
+    } else {
+        keccak256(code)
+    }
+}
+
+impl<DB, I, P> MonadEvm<DB, I, P>
+where
+    DB: Database,
+{
+    /// Applies `account_overrides` and `block_override` to the journaled state and
+    /// [`BlockEnv`] respectively, returning an [`OverrideJournal`] that can be passed to
+    /// [`revert_overrides`](Self::revert_overrides) to restore the prior state.
+    pub fn apply_overrides(
+        &mut self,
+        account_overrides: &HashMap<Address, AccountOverride>,
+        block_override: Option<&BlockOverride>,
+    ) -> Result<OverrideJournal, <DB as Database>::Error> {
+        let mut accounts = Vec::with_capacity(account_overrides.len());
+
+        for (&address, account_override) in account_overrides {
+            // Check the live journal first: a reused `MonadEvm` (e.g. across
+            // `MonadBlockExecutor` transactions) may already hold warmer, more current state
+            // for `address` than the backing database. Falling straight to `database.basic`
+            // would snapshot stale data and, on revert, clobber real state that was applied
+            // after the account was last loaded from the database.
+            let prior: Option<Account> = match self.ctx().journaled_state.state.get(&address) {
+                Some(account) => Some(account.clone()),
+                None => self.ctx_mut().journaled_state.database.basic(address)?.map(Account::from),
+            };
+            accounts.push(AccountSnapshot { address, account: prior.clone() });
+
+            let mut account = prior.unwrap_or_else(|| Account::from(revm::state::AccountInfo::default()));
+
+            if let Some(balance) = account_override.balance {
+                account.info.balance = balance;
+            }
+            if let Some(nonce) = account_override.nonce {
+                account.info.nonce = nonce;
+            }
+            if let Some(code) = &account_override.code {
+                account.info.code_hash = code_hash_for(code);
+                account.info.code = Some(Bytecode::new_raw(code.clone()));
+            }
+            if let Some(state) = &account_override.state {
+                account.storage.clear();
+                // A created account reads unlisted slots as zero instead of loading them
+                // from the database, so `state` replaces the storage as a whole.
+                account.mark_created();
+                for (slot, value) in state {
+                    account.storage.insert((*slot).into(), (*value).into());
+                }
+            } else if let Some(diff) = &account_override.state_diff {
+                for (slot, value) in diff {
+                    account.storage.insert((*slot).into(), (*value).into());
+                }
+            }
+
+            self.ctx_mut().journaled_state.state.insert(address, account);
+        }
+
+        let block = block_override.map(|over| {
+            let prior = self.block().clone();
+            let block = self.ctx_mut();
+            if let Some(number) = over.number {
+                block.block.number = number;
+            }
+            if let Some(timestamp) = over.timestamp {
+                block.block.timestamp = U256::from(timestamp);
+            }
+            if let Some(gas_limit) = over.gas_limit {
+                block.block.gas_limit = gas_limit;
+            }
+            if let Some(coinbase) = over.coinbase {
+                block.block.beneficiary = coinbase;
+            }
+            if let Some(base_fee) = over.base_fee {
+                block.block.basefee = base_fee.saturating_to();
+            }
+            BlockSnapshot(prior)
+        });
+
+        Ok(OverrideJournal { accounts, block })
+    }
+
+    /// Applies `account_overrides` and `block_override`, runs `f`, then reverts the
+    /// overrides regardless of `f`'s outcome, so the same [`MonadEvm`] can be reused
+    /// afterward.
+    pub fn with_state_overrides<R>(
+        &mut self,
+        account_overrides: &HashMap<Address, AccountOverride>,
+        block_override: Option<&BlockOverride>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> Result<R, <DB as Database>::Error> {
+        let journal = self.apply_overrides(account_overrides, block_override)?;
+        let result = f(self);
+        self.revert_overrides(journal);
+        Ok(result)
+    }
+
+    /// Reverts a previously applied [`OverrideJournal`], restoring the journaled state and
+    /// [`BlockEnv`] to what they were before [`apply_overrides`](Self::apply_overrides),
+    /// so the same [`MonadEvm`] can be reused.
+    pub fn revert_overrides(&mut self, journal: OverrideJournal) {
+        for snapshot in journal.accounts {
+            match snapshot.account {
+                Some(account) => {
+                    self.ctx_mut().journaled_state.state.insert(snapshot.address, account);
+                }
+                None => {
+                    self.ctx_mut().journaled_state.state.remove(&snapshot.address);
+                }
+            }
+        }
+
+        if let Some(BlockSnapshot(prior)) = journal.block {
+            self.ctx_mut().block = prior;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes;
+
+    #[test]
+    fn code_hash_for_empty_code_is_keccak_empty() {
+        assert_eq!(code_hash_for(&Bytes::new()), KECCAK_EMPTY);
+    }
+
+    #[test]
+    fn code_hash_for_matches_keccak256_of_code() {
+        let code = bytes!("6001600101");
+        assert_eq!(code_hash_for(&code), keccak256(&code));
+        assert_ne!(code_hash_for(&code), KECCAK_EMPTY);
+    }
+
+    mod round_trip {
+        use super::*;
+        use crate::MonadEvmFactory;
+        use alloy_evm::{EvmEnv, EvmFactory};
+        use monad_revm::MonadSpecId;
+        use revm::{
+            bytecode::Bytecode as RevmBytecode, context::CfgEnv, database::Database as RevmDatabase,
+            state::AccountInfo,
+        };
+        use std::convert::Infallible;
+
+        #[derive(Default, Clone)]
+        struct MockDb {
+            accounts: HashMap<Address, AccountInfo>,
+        }
+
+        impl RevmDatabase for MockDb {
+            type Error = Infallible;
+
+            fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+                Ok(self.accounts.get(&address).cloned())
+            }
+
+            fn code_by_hash(&mut self, _code_hash: B256) -> Result<RevmBytecode, Self::Error> {
+                Ok(RevmBytecode::default())
+            }
+
+            fn storage(&mut self, _address: Address, _index: U256) -> Result<U256, Self::Error> {
+                Ok(U256::ZERO)
+            }
+
+            fn block_hash(&mut self, _number: u64) -> Result<B256, Self::Error> {
+                Ok(B256::ZERO)
+            }
+        }
+
+        fn test_env() -> EvmEnv<MonadSpecId> {
+            EvmEnv { block_env: BlockEnv::default(), cfg_env: CfgEnv::new_with_spec(MonadSpecId::default()) }
+        }
+
+        #[test]
+        fn apply_overrides_then_revert_restores_prior_balance() {
+            let address = Address::from([1u8; 20]);
+            let mut accounts = HashMap::new();
+            accounts.insert(address, AccountInfo { balance: U256::from(1), ..Default::default() });
+            let db = MockDb { accounts };
+
+            let mut evm = MonadEvmFactory.create_evm(db, test_env());
+
+            let mut overrides = HashMap::new();
+            overrides.insert(address, AccountOverride { balance: Some(U256::from(42)), ..Default::default() });
+
+            let journal = evm.apply_overrides(&overrides, None).unwrap();
+            assert_eq!(evm.ctx().journaled_state.state.get(&address).unwrap().info.balance, U256::from(42));
+
+            evm.revert_overrides(journal);
+            assert_eq!(evm.ctx().journaled_state.state.get(&address).unwrap().info.balance, U256::from(1));
+        }
+
+        #[test]
+        fn apply_overrides_then_revert_restores_absent_account() {
+            let address = Address::from([2u8; 20]);
+            let mut evm = MonadEvmFactory.create_evm(MockDb::default(), test_env());
+
+            let mut overrides = HashMap::new();
+            overrides.insert(address, AccountOverride { balance: Some(U256::from(7)), ..Default::default() });
+
+            let journal = evm.apply_overrides(&overrides, None).unwrap();
+            assert!(evm.ctx().journaled_state.state.contains_key(&address));
+
+            evm.revert_overrides(journal);
+            assert!(!evm.ctx().journaled_state.state.contains_key(&address));
+        }
+
+        #[test]
+        fn apply_overrides_then_revert_restores_journaled_state_not_stale_db_snapshot() {
+            let address = Address::from([3u8; 20]);
+            let mut accounts = HashMap::new();
+            accounts.insert(address, AccountInfo { balance: U256::from(1), ..Default::default() });
+            let db = MockDb { accounts };
+
+            let mut evm = MonadEvmFactory.create_evm(db, test_env());
+
+            // Simulate a prior transaction on this same `MonadEvm` having already bumped the
+            // account's balance in the live journal, without touching the backing database.
+            evm.ctx_mut()
+                .journaled_state
+                .state
+                .insert(address, Account::from(AccountInfo { balance: U256::from(99), ..Default::default() }));
+
+            let mut overrides = HashMap::new();
+            overrides.insert(address, AccountOverride { balance: Some(U256::from(42)), ..Default::default() });
+
+            let journal = evm.apply_overrides(&overrides, None).unwrap();
+            assert_eq!(evm.ctx().journaled_state.state.get(&address).unwrap().info.balance, U256::from(42));
+
+            evm.revert_overrides(journal);
+            assert_eq!(evm.ctx().journaled_state.state.get(&address).unwrap().info.balance, U256::from(99));
+        }
+
+        #[test]
+        fn apply_overrides_then_revert_restores_block_env() {
+            let mut evm = MonadEvmFactory.create_evm(MockDb::default(), test_env());
+            let prior_number = evm.block().number;
+
+            let block_override = BlockOverride { number: Some(prior_number + U256::from(1)), ..Default::default() };
+            let journal = evm.apply_overrides(&HashMap::new(), Some(&block_override)).unwrap();
+            assert_eq!(evm.block().number, prior_number + U256::from(1));
+
+            evm.revert_overrides(journal);
+            assert_eq!(evm.block().number, prior_number);
+        }
+    }
+}
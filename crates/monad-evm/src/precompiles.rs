@@ -0,0 +1,147 @@
+//! Runtime-extensible precompile registry layered on top of the spec-derived
+//! [`MonadPrecompiles`] defaults.
+
+use alloy_primitives::Address;
+use monad_revm::{precompiles::MonadPrecompiles, MonadSpecId};
+use revm::{
+    context::Cfg,
+    handler::PrecompileProvider,
+    interpreter::{InputsImpl, InterpreterResult},
+};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::MonadContext;
+
+/// A single injected precompile: native code reachable at a fixed [`Address`].
+pub trait Precompile: Send + Sync {
+    /// Executes the precompile against `input`, consuming up to `gas_limit` gas, producing
+    /// an [`InterpreterResult`].
+    fn run(&self, input: &InputsImpl, is_static: bool, gas_limit: u64) -> InterpreterResult;
+}
+
+/// Precompile registry that consults a set of injected precompiles first and falls back to
+/// the spec-derived [`MonadPrecompiles`] for anything not overridden.
+#[derive(Clone)]
+pub struct MonadPrecompilesWithExtra {
+    extra: Arc<HashMap<Address, Arc<dyn Precompile>>>,
+    defaults: MonadPrecompiles,
+}
+
+impl MonadPrecompilesWithExtra {
+    /// Builds a registry from `extra` (consulted first) layered over `defaults`.
+    pub fn new(extra: Arc<HashMap<Address, Arc<dyn Precompile>>>, defaults: MonadPrecompiles) -> Self {
+        Self { extra, defaults }
+    }
+
+    /// Addresses of the injected precompiles, in no particular order.
+    pub fn extra_addresses(&self) -> impl Iterator<Item = Address> + '_ {
+        self.extra.keys().copied()
+    }
+}
+
+impl<DB: revm::Database> PrecompileProvider<MonadContext<DB>> for MonadPrecompilesWithExtra {
+    type Output = InterpreterResult;
+
+    fn set_spec(&mut self, spec: <<MonadContext<DB> as revm::context::ContextTr>::Cfg as Cfg>::Spec) -> bool {
+        PrecompileProvider::<MonadContext<DB>>::set_spec(&mut self.defaults, spec)
+    }
+
+    fn run(
+        &mut self,
+        context: &mut MonadContext<DB>,
+        address: &Address,
+        inputs: &InputsImpl,
+        is_static: bool,
+        gas_limit: u64,
+    ) -> Result<Option<Self::Output>, String> {
+        if let Some(precompile) = self.extra.get(address) {
+            return Ok(Some(precompile.run(inputs, is_static, gas_limit)));
+        }
+        self.defaults.run(context, address, inputs, is_static, gas_limit)
+    }
+
+    fn warm_addresses(&self) -> Box<dyn Iterator<Item = Address> + '_> {
+        Box::new(self.extra.keys().copied().chain(self.defaults.warm_addresses()))
+    }
+
+    fn contains(&self, address: &Address) -> bool {
+        self.extra.contains_key(address) || self.defaults.contains(address)
+    }
+}
+
+/// Builds a [`MonadPrecompilesWithExtra`] around the spec-derived [`MonadPrecompiles`]
+/// defaults, adding or overriding individual addresses.
+#[derive(Default)]
+pub struct MonadPrecompilesBuilder {
+    extra: HashMap<Address, Arc<dyn Precompile>>,
+}
+
+impl MonadPrecompilesBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `precompile` at `address`, taking priority over any spec-derived default
+    /// at the same address. Registering a second precompile at an already-registered
+    /// address replaces the first.
+    #[must_use]
+    pub fn with_precompile(mut self, address: Address, precompile: Arc<dyn Precompile>) -> Self {
+        self.extra.insert(address, precompile);
+        self
+    }
+
+    /// Addresses registered on this builder so far, in no particular order.
+    pub fn addresses(&self) -> impl Iterator<Item = Address> + '_ {
+        self.extra.keys().copied()
+    }
+
+    /// Finishes the builder, layering the registered precompiles over `defaults`.
+    pub fn build(self, defaults: MonadPrecompiles) -> MonadPrecompilesWithExtra {
+        MonadPrecompilesWithExtra::new(Arc::new(self.extra), defaults)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use revm::interpreter::{Gas, InstructionResult};
+    use std::collections::HashSet;
+
+    struct FakePrecompile(u64);
+
+    impl Precompile for FakePrecompile {
+        fn run(&self, _input: &InputsImpl, _is_static: bool, gas_limit: u64) -> InterpreterResult {
+            InterpreterResult {
+                result: InstructionResult::Return,
+                output: Default::default(),
+                gas: Gas::new(gas_limit.min(self.0)),
+            }
+        }
+    }
+
+    #[test]
+    fn builder_tracks_registered_addresses() {
+        let a = address!("0000000000000000000000000000000000000001");
+        let b = address!("0000000000000000000000000000000000000002");
+
+        let builder = MonadPrecompilesBuilder::new()
+            .with_precompile(a, Arc::new(FakePrecompile(1)))
+            .with_precompile(b, Arc::new(FakePrecompile(2)));
+
+        let addresses: HashSet<_> = builder.addresses().collect();
+        assert_eq!(addresses, HashSet::from([a, b]));
+    }
+
+    #[test]
+    fn registering_same_address_twice_collapses_to_one_entry() {
+        let addr = address!("0000000000000000000000000000000000000001");
+
+        let builder = MonadPrecompilesBuilder::new()
+            .with_precompile(addr, Arc::new(FakePrecompile(1)))
+            .with_precompile(addr, Arc::new(FakePrecompile(2)));
+
+        assert_eq!(builder.addresses().count(), 1);
+    }
+}
@@ -0,0 +1,218 @@
+//! Geth-compatible `callTracer` built on the call/create inspector hooks.
+
+use alloy_primitives::{Address, Bytes, U256};
+use revm::{
+    context::ContextTr,
+    context_interface::CreateScheme,
+    interpreter::{CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome},
+    Inspector,
+};
+
+/// Kind of call that produced a [`CallFrame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallFrameKind {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+    Create,
+    Create2,
+    SelfDestruct,
+}
+
+/// A single frame in the call tree produced by [`MonadCallTracer`], matching Geth's
+/// `callTracer` frame shape.
+#[derive(Debug, Clone)]
+pub struct CallFrame {
+    /// Kind of call.
+    pub kind: CallFrameKind,
+    /// Caller of this frame.
+    pub from: Address,
+    /// Callee of this frame, `None` for a contract creation that reverted before an
+    /// address was assigned.
+    pub to: Option<Address>,
+    /// Value transferred with the call.
+    pub value: U256,
+    /// Calldata (or init code, for creations).
+    pub input: Bytes,
+    /// Gas made available to the frame.
+    pub gas: u64,
+    /// Gas consumed by the frame.
+    pub gas_used: u64,
+    /// Return data (or deployed code, for creations).
+    pub output: Bytes,
+    /// Error message if the frame reverted or halted.
+    pub error: Option<String>,
+    /// Nested calls made by this frame, in call order.
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    fn pending(kind: CallFrameKind, from: Address, to: Option<Address>, value: U256, input: Bytes, gas: u64) -> Self {
+        Self { kind, from, to, value, input, gas, gas_used: 0, output: Bytes::new(), error: None, calls: Vec::new() }
+    }
+}
+
+/// [`Inspector`] that assembles a tree of [`CallFrame`]s for a transaction, matching Geth's
+/// `callTracer`.
+#[derive(Debug, Default)]
+pub struct MonadCallTracer {
+    stack: Vec<CallFrame>,
+    root: Option<CallFrame>,
+}
+
+impl MonadCallTracer {
+    /// Creates a new, empty call tracer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes the tracer, returning the root [`CallFrame`] of the call tree, if any call
+    /// was made.
+    pub fn into_root_frame(self) -> Option<CallFrame> {
+        self.root
+    }
+
+    fn push(&mut self, frame: CallFrame) {
+        self.stack.push(frame);
+    }
+
+    fn pop_finished(&mut self, gas_used: u64, output: Bytes, error: Option<String>) {
+        if let Some(mut frame) = self.stack.pop() {
+            frame.gas_used = gas_used;
+            frame.output = output;
+            frame.error = error;
+            if let Some(parent) = self.stack.last_mut() {
+                parent.calls.push(frame);
+            } else {
+                self.root = Some(frame);
+            }
+        }
+    }
+}
+
+impl<CTX> Inspector<CTX> for MonadCallTracer
+where
+    CTX: ContextTr,
+{
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let kind = match inputs.scheme {
+            _ if inputs.is_static => CallFrameKind::StaticCall,
+            CallScheme::Call => CallFrameKind::Call,
+            CallScheme::CallCode => CallFrameKind::CallCode,
+            CallScheme::DelegateCall => CallFrameKind::DelegateCall,
+            CallScheme::StaticCall => CallFrameKind::StaticCall,
+        };
+        self.push(CallFrame::pending(
+            kind,
+            inputs.caller,
+            Some(inputs.bytecode_address),
+            inputs.value.get(),
+            inputs.input.bytes(_context),
+            inputs.gas_limit,
+        ));
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        let gas_used = outcome.gas().spent();
+        let error = (!outcome.result.is_ok()).then(|| format!("{:?}", outcome.instruction_result()));
+        self.pop_finished(gas_used, outcome.output().clone(), error);
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        let kind = match inputs.scheme {
+            CreateScheme::Create2 { .. } => CallFrameKind::Create2,
+            _ => CallFrameKind::Create,
+        };
+        self.push(CallFrame::pending(
+            kind,
+            inputs.caller,
+            None,
+            inputs.value,
+            inputs.init_code.clone(),
+            inputs.gas_limit,
+        ));
+        None
+    }
+
+    fn create_end(&mut self, _context: &mut CTX, _inputs: &CreateInputs, outcome: &mut CreateOutcome) {
+        let gas_used = outcome.gas().spent();
+        let error = (!outcome.result.is_ok()).then(|| format!("{:?}", outcome.instruction_result()));
+        let output = outcome.output().clone();
+        if let Some(frame) = self.stack.last_mut() {
+            frame.to = outcome.address;
+        }
+        self.pop_finished(gas_used, output, error);
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.calls.push(CallFrame {
+                kind: CallFrameKind::SelfDestruct,
+                from: contract,
+                to: Some(target),
+                value,
+                input: Bytes::new(),
+                gas: 0,
+                gas_used: 0,
+                output: Bytes::new(),
+                error: None,
+                calls: Vec::new(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    fn frame(kind: CallFrameKind) -> CallFrame {
+        CallFrame::pending(
+            kind,
+            address!("0000000000000000000000000000000000000001"),
+            Some(address!("0000000000000000000000000000000000000002")),
+            U256::ZERO,
+            Bytes::new(),
+            21_000,
+        )
+    }
+
+    #[test]
+    fn single_call_becomes_root_frame() {
+        let mut tracer = MonadCallTracer::new();
+        tracer.push(frame(CallFrameKind::Call));
+        tracer.pop_finished(100, Bytes::new(), None);
+
+        let root = tracer.into_root_frame().expect("root frame");
+        assert_eq!(root.kind, CallFrameKind::Call);
+        assert_eq!(root.gas_used, 100);
+        assert!(root.calls.is_empty());
+    }
+
+    #[test]
+    fn nested_call_is_attached_to_parent() {
+        let mut tracer = MonadCallTracer::new();
+        tracer.push(frame(CallFrameKind::Call));
+        tracer.push(frame(CallFrameKind::DelegateCall));
+        tracer.pop_finished(10, Bytes::new(), None);
+        tracer.pop_finished(50, Bytes::new(), None);
+
+        let root = tracer.into_root_frame().expect("root frame");
+        assert_eq!(root.calls.len(), 1);
+        assert_eq!(root.calls[0].kind, CallFrameKind::DelegateCall);
+        assert_eq!(root.calls[0].gas_used, 10);
+    }
+
+    #[test]
+    fn pop_finished_records_error() {
+        let mut tracer = MonadCallTracer::new();
+        tracer.push(frame(CallFrameKind::Call));
+        tracer.pop_finished(10, Bytes::new(), Some("Revert".to_string()));
+
+        let root = tracer.into_root_frame().expect("root frame");
+        assert_eq!(root.error.as_deref(), Some("Revert"));
+    }
+}
@@ -0,0 +1,16 @@
+//! Built-in [`Inspector`](revm::Inspector) implementations providing Geth-compatible
+//! `debug_trace*` tracers out of the box.
+
+mod call_tracer;
+mod prestate_tracer;
+mod struct_log;
+
+pub use call_tracer::{CallFrame, CallFrameKind, MonadCallTracer};
+pub use prestate_tracer::{AccountState, MonadPrestateTracer, PrestateTrace, PrestateTracerMode};
+pub use struct_log::{GethTrace, MonadStructLogger, StructLog, StructLogConfig};
+
+/// Converts a storage value to its big-endian, 32-byte representation, as used for both
+/// storage slots and values throughout this module's `SLOAD`/`SSTORE` capture.
+pub(super) fn b256_from_u256(value: alloy_primitives::U256) -> alloy_primitives::B256 {
+    alloy_primitives::B256::from(value.to_be_bytes::<32>())
+}
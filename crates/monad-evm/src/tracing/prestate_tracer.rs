@@ -0,0 +1,217 @@
+//! Geth-compatible `prestateTracer` built on the call/create inspector hooks.
+
+use alloy_primitives::{Address, Bytes, B256, U256};
+use revm::{
+    bytecode::opcode,
+    context::ContextTr,
+    database::Database,
+    interpreter::{CallInputs, CreateInputs, Interpreter},
+    Inspector,
+};
+use std::collections::{BTreeMap, HashMap};
+
+use super::b256_from_u256;
+
+/// Snapshot of a single account's balance, nonce, code and storage.
+#[derive(Debug, Clone, Default)]
+pub struct AccountState {
+    /// Account balance.
+    pub balance: U256,
+    /// Account nonce.
+    pub nonce: u64,
+    /// Deployed bytecode, if any.
+    pub code: Option<Bytes>,
+    /// Storage slots read or written on this account.
+    pub storage: BTreeMap<B256, B256>,
+}
+
+/// Mode in which [`MonadPrestateTracer`] reports state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrestateTracerMode {
+    /// Report only the pre-execution state of every touched account.
+    #[default]
+    Prestate,
+    /// Report both pre- and post-execution state of every touched account.
+    Diff,
+}
+
+/// Pre- (and optionally post-) execution state of every account touched during a
+/// transaction, matching Geth's `prestateTracer`.
+#[derive(Debug, Clone, Default)]
+pub struct PrestateTrace {
+    /// Account state before execution, keyed by address.
+    pub pre: HashMap<Address, AccountState>,
+    /// Account state after execution, keyed by address. Only populated in
+    /// [`PrestateTracerMode::Diff`].
+    pub post: HashMap<Address, AccountState>,
+}
+
+/// [`Inspector`] that records the pre- (and, in diff mode, post-) execution state of every
+/// account touched during a transaction, matching Geth's `prestateTracer`.
+#[derive(Debug, Default)]
+pub struct MonadPrestateTracer {
+    mode: PrestateTracerMode,
+    touched: Vec<Address>,
+    /// Stack of the account whose storage is being read/written by the currently
+    /// executing frame, pushed on `call`/`create` and popped on `call_end`/`create_end`.
+    address_stack: Vec<Address>,
+    /// Storage slots read or written on each touched account, keyed by address.
+    storage: HashMap<Address, BTreeMap<B256, B256>>,
+    pending_sload_slot: Option<B256>,
+}
+
+impl MonadPrestateTracer {
+    /// Creates a new tracer operating in the given mode.
+    pub fn new(mode: PrestateTracerMode) -> Self {
+        Self { mode, ..Default::default() }
+    }
+
+    fn record_touch(&mut self, address: Address) {
+        if !self.touched.contains(&address) {
+            self.touched.push(address);
+        }
+    }
+
+    /// Consumes the tracer, loading the recorded addresses' state out of `db` to build the
+    /// final [`PrestateTrace`].
+    ///
+    /// Must be called with the *pre-execution* database for the `pre` half, and (in
+    /// [`PrestateTracerMode::Diff`]) again with the *post-execution* database to fill in
+    /// `post`.
+    pub fn load_account_states<DB: Database>(
+        addresses: &[Address],
+        storage: &HashMap<Address, BTreeMap<B256, B256>>,
+        db: &mut DB,
+    ) -> HashMap<Address, AccountState> {
+        let mut states = HashMap::with_capacity(addresses.len());
+        for &address in addresses {
+            let Ok(account) = db.basic(address) else { continue };
+            let Some(account) = account else { continue };
+            let code = account.code.as_ref().map(|code| Bytes::copy_from_slice(code.bytes_slice()));
+            // Only the recorded slot keys are used; their values are read from `db`, since
+            // the recorded values may already reflect later `SSTORE`s.
+            let account_storage = storage.get(&address).map_or_else(BTreeMap::new, |slots| {
+                slots
+                    .keys()
+                    .filter_map(|slot| {
+                        let value = db.storage(address, U256::from_be_bytes(slot.0)).ok()?;
+                        Some((*slot, b256_from_u256(value)))
+                    })
+                    .collect()
+            });
+            states.insert(
+                address,
+                AccountState { balance: account.balance, nonce: account.nonce, code, storage: account_storage },
+            );
+        }
+        states
+    }
+
+    /// Addresses touched during execution, in first-touch order.
+    pub fn touched_addresses(&self) -> &[Address] {
+        &self.touched
+    }
+
+    /// Storage slots recorded via `SLOAD`/`SSTORE`, keyed by the account whose storage they
+    /// belong to.
+    pub fn storage(&self) -> &HashMap<Address, BTreeMap<B256, B256>> {
+        &self.storage
+    }
+
+    /// Tracer mode this instance was constructed with.
+    pub const fn mode(&self) -> PrestateTracerMode {
+        self.mode
+    }
+}
+
+impl<CTX> Inspector<CTX> for MonadPrestateTracer
+where
+    CTX: ContextTr,
+{
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<revm::interpreter::CallOutcome> {
+        self.record_touch(inputs.caller);
+        self.record_touch(inputs.bytecode_address);
+        self.address_stack.push(inputs.target_address);
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, _outcome: &mut revm::interpreter::CallOutcome) {
+        self.address_stack.pop();
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<revm::interpreter::CreateOutcome> {
+        self.record_touch(inputs.caller);
+        // The created account's address isn't known until `create_end`; track the creator
+        // in the meantime so any storage touched by the init code itself isn't lost.
+        self.address_stack.push(inputs.caller);
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut revm::interpreter::CreateOutcome,
+    ) {
+        self.address_stack.pop();
+        if let Some(address) = outcome.address {
+            self.record_touch(address);
+        }
+    }
+
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut CTX) {
+        match interp.bytecode.opcode() {
+            opcode::SLOAD => {
+                self.pending_sload_slot = interp.stack.data().last().copied().map(b256_from_u256);
+            }
+            opcode::SSTORE => {
+                let stack = interp.stack.data();
+                if let ([.., value, slot], Some(&address)) = (stack.as_slice(), self.address_stack.last()) {
+                    self.storage.entry(address).or_default().insert(b256_from_u256(*slot), b256_from_u256(*value));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, _context: &mut CTX) {
+        if let Some(slot) = self.pending_sload_slot.take() {
+            if let (Some(value), Some(&address)) = (interp.stack.data().last().copied(), self.address_stack.last()) {
+                self.storage.entry(address).or_default().insert(slot, b256_from_u256(value));
+            }
+        }
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, _value: U256) {
+        self.record_touch(contract);
+        self.record_touch(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    #[test]
+    fn record_touch_dedupes_addresses() {
+        let mut tracer = MonadPrestateTracer::new(PrestateTracerMode::Prestate);
+        let addr = address!("0000000000000000000000000000000000000001");
+        tracer.record_touch(addr);
+        tracer.record_touch(addr);
+        assert_eq!(tracer.touched_addresses(), &[addr]);
+    }
+
+    #[test]
+    fn diff_mode_is_not_default() {
+        assert_eq!(PrestateTracerMode::default(), PrestateTracerMode::Prestate);
+        assert_ne!(PrestateTracerMode::default(), PrestateTracerMode::Diff);
+    }
+
+    #[test]
+    fn storage_starts_empty() {
+        let tracer = MonadPrestateTracer::new(PrestateTracerMode::Diff);
+        assert!(tracer.storage().is_empty());
+        let _ = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+    }
+}
@@ -0,0 +1,295 @@
+//! Geth-style `debug_traceTransaction` struct-log tracer.
+
+use alloy_primitives::{Bytes, B256, U256};
+use revm::{
+    bytecode::{opcode, OpCode},
+    context::ContextTr,
+    interpreter::{
+        interpreter_types::Jumps, CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter,
+    },
+    Inspector,
+};
+use std::collections::BTreeMap;
+
+/// Controls which optional, expensive-to-capture pieces of state are recorded at each
+/// step.
+#[derive(Debug, Clone, Copy)]
+pub struct StructLogConfig {
+    /// Capture the full stack at each step.
+    pub with_stack: bool,
+    /// Capture the full memory at each step.
+    pub with_memory: bool,
+    /// Capture storage slots read or written during the current call frame.
+    pub with_storage: bool,
+}
+
+impl Default for StructLogConfig {
+    fn default() -> Self {
+        Self { with_stack: true, with_memory: true, with_storage: true }
+    }
+}
+
+/// A single opcode-level entry in a [`GethTrace`], matching the `debug_traceTransaction`
+/// default (struct-log) format.
+#[derive(Debug, Clone)]
+pub struct StructLog {
+    /// Program counter of the executed instruction.
+    pub pc: u64,
+    /// Mnemonic of the executed opcode.
+    pub op: &'static str,
+    /// Gas remaining before executing this instruction.
+    pub gas: u64,
+    /// Gas consumed by this instruction (gas-before minus gas-after).
+    pub gas_cost: u64,
+    /// Call depth at which this instruction executed.
+    pub depth: u64,
+    /// Error message, if this step reverted or halted the call.
+    pub error: Option<String>,
+    /// Snapshot of the stack, top last, if [`StructLogConfig::with_stack`] is enabled.
+    pub stack: Option<Vec<U256>>,
+    /// Snapshot of memory if [`StructLogConfig::with_memory`] is enabled.
+    pub memory: Option<Bytes>,
+    /// Storage slots read or written by `SLOAD`/`SSTORE` in the current call frame (not
+    /// including nested calls), if [`StructLogConfig::with_storage`] is enabled.
+    pub storage: Option<BTreeMap<B256, B256>>,
+}
+
+/// Assembled trace for a single transaction, matching the `debug_traceTransaction` default
+/// format.
+#[derive(Debug, Clone)]
+pub struct GethTrace {
+    /// Total gas used by the transaction.
+    pub gas_used: u64,
+    /// Whether the transaction reverted or halted.
+    pub failed: bool,
+    /// Return data of the transaction.
+    pub return_value: Bytes,
+    /// Per-opcode struct logs, in execution order.
+    pub struct_logs: Vec<StructLog>,
+}
+
+/// [`Inspector`] that records a [`GethTrace`] struct-log for every step of execution.
+#[derive(Debug, Clone, Default)]
+pub struct MonadStructLogger {
+    config: StructLogConfig,
+    struct_logs: Vec<StructLog>,
+    gas_before_step: u64,
+    pc: u64,
+    op: &'static str,
+    /// Stack of per-call-frame storage maps, one per currently open call/create frame.
+    /// Pushed on `call`/`create` entry and popped on exit, so a nested call's `SLOAD`s and
+    /// `SSTORE`s never leak into the caller's still-open frame and vice versa.
+    frame_storage: Vec<BTreeMap<B256, B256>>,
+    pending_sload_slot: Option<B256>,
+}
+
+impl MonadStructLogger {
+    /// Creates a new logger with the given capture configuration.
+    pub fn new(config: StructLogConfig) -> Self {
+        Self { config, frame_storage: vec![BTreeMap::new()], ..Default::default() }
+    }
+
+    /// Consumes the logger, assembling the final [`GethTrace`].
+    ///
+    /// `gas_used`, `failed` and `return_value` come from the transaction's
+    /// [`ExecutionResult`](revm::context_interface::result::ExecutionResult), since the
+    /// inspector has no visibility into the outcome once execution completes.
+    pub fn into_trace(self, gas_used: u64, failed: bool, return_value: Bytes) -> GethTrace {
+        GethTrace { gas_used, failed, return_value, struct_logs: self.struct_logs }
+    }
+}
+
+impl<CTX> Inspector<CTX> for MonadStructLogger
+where
+    CTX: ContextTr,
+{
+    fn step(&mut self, interp: &mut Interpreter, _context: &mut CTX) {
+        self.gas_before_step = interp.control.gas().remaining();
+        // pc/op must be captured here, before this instruction executes: by the time
+        // `step_end` fires, the interpreter's bytecode cursor has already advanced past it
+        // (and for JUMP*/PUSHn the new position has nothing to do with the instruction that
+        // just ran).
+        self.pc = interp.bytecode.pc() as u64;
+        self.op = OpCode::new(interp.bytecode.opcode()).map_or("unknown", OpCode::as_str);
+
+        if self.config.with_storage {
+            match interp.bytecode.opcode() {
+                opcode::SLOAD => {
+                    self.pending_sload_slot = interp.stack.data().last().copied().map(crate::tracing::b256_from_u256);
+                }
+                opcode::SSTORE => {
+                    let stack = interp.stack.data();
+                    if let [.., value, slot] = stack.as_slice() {
+                        self.current_frame_storage()
+                            .insert(crate::tracing::b256_from_u256(*slot), crate::tracing::b256_from_u256(*value));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, _context: &mut CTX) {
+        let gas = self.gas_before_step;
+        let gas_after = interp.control.gas().remaining();
+        let gas_cost = gas.saturating_sub(gas_after);
+
+        if let Some(slot) = self.pending_sload_slot.take() {
+            if let Some(value) = interp.stack.data().last().copied() {
+                self.current_frame_storage().insert(slot, crate::tracing::b256_from_u256(value));
+            }
+        }
+
+        let instruction_result = interp.control.instruction_result();
+        let error = instruction_result.is_error().then(|| format!("{instruction_result:?}"));
+
+        let stack = self.config.with_stack.then(|| interp.stack.data().clone());
+        let memory =
+            self.config.with_memory.then(|| Bytes::copy_from_slice(interp.memory.context_memory()));
+        let storage = self.config.with_storage.then(|| self.current_frame_storage().clone());
+
+        self.struct_logs.push(StructLog {
+            pc: self.pc,
+            op: self.op,
+            gas,
+            gas_cost,
+            depth: interp.control.call_depth() as u64,
+            error,
+            stack,
+            memory,
+            storage,
+        });
+    }
+
+    fn call(&mut self, _context: &mut CTX, _inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.frame_storage.push(BTreeMap::new());
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, _outcome: &mut CallOutcome) {
+        self.frame_storage.pop();
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.frame_storage.push(BTreeMap::new());
+        None
+    }
+
+    fn create_end(&mut self, _context: &mut CTX, _inputs: &CreateInputs, _outcome: &mut CreateOutcome) {
+        self.frame_storage.pop();
+    }
+}
+
+impl MonadStructLogger {
+    /// The storage map for the currently executing call frame. Always non-empty: `new`
+    /// seeds the root frame and every `call`/`create` push is matched by a `call_end`/
+    /// `create_end` pop before this is called again.
+    fn current_frame_storage(&mut self) -> &mut BTreeMap<B256, B256> {
+        self.frame_storage.last_mut().expect("root frame is never popped")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::uint;
+
+    #[test]
+    fn b256_from_u256_round_trips_through_be_bytes() {
+        let value = uint!(0x1234_U256);
+        assert_eq!(crate::tracing::b256_from_u256(value), B256::from(value.to_be_bytes::<32>()));
+        assert_eq!(U256::from_be_bytes(crate::tracing::b256_from_u256(value).0), value);
+    }
+
+    #[test]
+    fn struct_log_config_default_captures_everything() {
+        let config = StructLogConfig::default();
+        assert!(config.with_stack);
+        assert!(config.with_memory);
+        assert!(config.with_storage);
+    }
+
+    mod execution {
+        use super::*;
+        use crate::MonadEvmFactory;
+        use alloy_evm::{EvmEnv, EvmFactory};
+        use alloy_primitives::{address, bytes, TxKind};
+        use monad_revm::MonadSpecId;
+        use revm::{
+            bytecode::Bytecode as RevmBytecode,
+            context::{BlockEnv, CfgEnv, TxEnv},
+            database::Database as RevmDatabase,
+            state::AccountInfo,
+        };
+        use std::{collections::HashMap, convert::Infallible};
+
+        #[derive(Default)]
+        struct MockDb {
+            accounts: HashMap<alloy_primitives::Address, AccountInfo>,
+        }
+
+        impl RevmDatabase for MockDb {
+            type Error = Infallible;
+
+            fn basic(&mut self, address: alloy_primitives::Address) -> Result<Option<AccountInfo>, Self::Error> {
+                Ok(self.accounts.get(&address).cloned())
+            }
+
+            fn code_by_hash(&mut self, _code_hash: B256) -> Result<RevmBytecode, Self::Error> {
+                Ok(RevmBytecode::default())
+            }
+
+            fn storage(&mut self, _address: alloy_primitives::Address, _index: U256) -> Result<U256, Self::Error> {
+                Ok(U256::ZERO)
+            }
+
+            fn block_hash(&mut self, _number: u64) -> Result<B256, Self::Error> {
+                Ok(B256::ZERO)
+            }
+        }
+
+        fn test_env() -> EvmEnv<MonadSpecId> {
+            EvmEnv { block_env: BlockEnv::default(), cfg_env: CfgEnv::new_with_spec(MonadSpecId::default()) }
+        }
+
+        #[test]
+        fn step_records_pc_and_op_of_the_instruction_that_just_executed() {
+            let caller = address!("0000000000000000000000000000000000000001");
+            let contract = address!("0000000000000000000000000000000000000002");
+
+            let mut accounts = HashMap::new();
+            accounts.insert(caller, AccountInfo { balance: U256::from(1_000_000_000_u64), ..Default::default() });
+            // PUSH1 0x00 PUSH1 0x00 SSTORE
+            accounts.insert(
+                contract,
+                AccountInfo {
+                    code: Some(RevmBytecode::new_raw(bytes!("6000600055"))),
+                    ..Default::default()
+                },
+            );
+            let db = MockDb { accounts };
+
+            let mut evm = MonadEvmFactory.create_evm_with_inspector(
+                db,
+                test_env(),
+                MonadStructLogger::new(StructLogConfig::default()),
+            );
+
+            let tx = TxEnv {
+                caller,
+                kind: TxKind::Call(contract),
+                gas_limit: 1_000_000,
+                ..Default::default()
+            };
+            let (_, trace) = evm.transact_with_struct_logs(tx).unwrap();
+
+            assert!(trace.struct_logs.len() >= 3);
+            assert_eq!(trace.struct_logs[0].pc, 0);
+            assert_eq!(trace.struct_logs[0].op, "PUSH1");
+            assert_eq!(trace.struct_logs[1].pc, 2);
+            assert_eq!(trace.struct_logs[1].op, "PUSH1");
+            assert_eq!(trace.struct_logs[2].pc, 4);
+            assert_eq!(trace.struct_logs[2].op, "SSTORE");
+        }
+    }
+}